@@ -48,5 +48,49 @@ fn bench_put_take_once(c: &mut Criterion) {
     group.finish();
 }
 
-criterion_group!(benches, bench_new, bench_put_take_once);
+fn bench_put_take_contended(c: &mut Criterion) {
+    use std::sync::atomic::{AtomicBool, Ordering};
+    use std::sync::Arc;
+    use std::thread;
+
+    let mvar = Arc::new(Mvar::empty());
+    let stop = Arc::new(AtomicBool::new(false));
+
+    // A background thread keeps racing the benchmarked thread's `take`s with its own `put`s, so
+    // these timings reflect contention instead of the uncontended round trip
+    // `bench_put_take_once` measures above — this is the scenario `fast-path`'s `try_lock`
+    // backoff is meant to help with; run this with and without `--features fast-path` to compare.
+    let producer = {
+        let mvar = Arc::clone(&mvar);
+        let stop = Arc::clone(&stop);
+        thread::spawn(move || {
+            while !stop.load(Ordering::Relaxed) {
+                let _ = mvar.put(());
+            }
+        })
+    };
+
+    let mut group = c.benchmark_group("put-take-contended");
+    group.bench_function(BenchmarkId::new("mvar", 1), |b| {
+        b.iter(|| {
+            mvar.take().unwrap();
+            let _ = mvar.put(());
+        })
+    });
+    group.finish();
+
+    stop.store(true, Ordering::Relaxed);
+    while !producer.is_finished() {
+        let _ = mvar.try_take();
+        let _ = mvar.try_put(());
+    }
+    producer.join().unwrap();
+}
+
+criterion_group!(
+    benches,
+    bench_new,
+    bench_put_take_once,
+    bench_put_take_contended
+);
 criterion_main!(benches);
@@ -0,0 +1,337 @@
+//! An async counterpart to [`Mvar`](crate::Mvar) for use inside `Future`-based executors.
+//!
+//! Blocking `Mvar::take`/`put` would stall an executor thread if called from an async task.
+//! [`AsyncMvar`] instead exposes `take`/`put`/`read` as `Future`s that register a [`Waker`]
+//! and return [`Poll::Pending`] when the slot isn't ready yet.
+
+use std::collections::VecDeque;
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::{Mutex, PoisonError};
+use std::task::{Context, Poll, Waker};
+
+#[derive(Debug)]
+struct State<T> {
+    value: Option<T>,
+    // Takers remove the value, so only one of them can succeed per `put` — waking the oldest
+    // is enough.
+    take_waiters: VecDeque<Waker>,
+    // Readers don't remove the value, so every one of them can succeed once it's there —
+    // `put` must wake all of them, not just the oldest.
+    read_waiters: VecDeque<Waker>,
+    put_waiters: VecDeque<Waker>,
+}
+
+/// Registers `waker` in `queue`, keeping a prior registration for the same task instead of
+/// appending another, so a future that's polled (and still pending) many times doesn't
+/// accumulate unbounded duplicate wakers.
+fn register_waker(queue: &mut VecDeque<Waker>, waker: &Waker) {
+    if !queue.iter().any(|queued| queued.will_wake(waker)) {
+        queue.push_back(waker.clone());
+    }
+}
+
+/// An async, single-slot rendezvous cell. See the [module docs](self) for details.
+#[derive(Debug)]
+pub struct AsyncMvar<T> {
+    state: Mutex<State<T>>,
+}
+
+impl<T> Default for AsyncMvar<T> {
+    /// Creates an empty `AsyncMvar`.
+    fn default() -> Self {
+        Self::empty()
+    }
+}
+
+impl<T> AsyncMvar<T> {
+    /// Creates an empty `AsyncMvar`.
+    pub fn empty() -> Self {
+        Self {
+            state: Mutex::new(State {
+                value: None,
+                take_waiters: VecDeque::new(),
+                read_waiters: VecDeque::new(),
+                put_waiters: VecDeque::new(),
+            }),
+        }
+    }
+
+    /// Creates an `AsyncMvar` which contains the value.
+    pub fn new(value: T) -> Self {
+        Self {
+            state: Mutex::new(State {
+                value: Some(value),
+                take_waiters: VecDeque::new(),
+                read_waiters: VecDeque::new(),
+                put_waiters: VecDeque::new(),
+            }),
+        }
+    }
+
+    fn lock(&self) -> std::sync::MutexGuard<'_, State<T>> {
+        // This lock only ever guards in-memory bookkeeping (the value slot and the waker
+        // queues) and is never held across an `.await`, so a poisoning panic here can't leave
+        // the `AsyncMvar` in a torn state; recovering it keeps the waker queues usable instead
+        // of turning every future poll into a panic.
+        self.state.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.lock().value.is_none()
+    }
+
+    /// Non-blocking; does not register a waker and never returns `Poll::Pending`.
+    pub fn try_take(&self) -> Option<T> {
+        let mut state = self.lock();
+        let value = state.value.take();
+        if value.is_some() {
+            if let Some(waker) = state.put_waiters.pop_front() {
+                waker.wake();
+            }
+        }
+        value
+    }
+
+    /// Non-blocking; does not register a waker and never returns `Poll::Pending`. On failure
+    /// the value is handed back so ownership isn't lost.
+    pub fn try_put(&self, value: T) -> Result<(), T> {
+        let mut state = self.lock();
+        if state.value.is_some() {
+            return Err(value);
+        }
+        state.value = Some(value);
+        if let Some(waker) = state.take_waiters.pop_front() {
+            waker.wake();
+        }
+        for waker in state.read_waiters.drain(..) {
+            waker.wake();
+        }
+        Ok(())
+    }
+
+    /// Returns a `Future` that resolves once the `AsyncMvar` is full, removing the value.
+    pub fn take(&self) -> Take<'_, T> {
+        Take {
+            mvar: self,
+            waker: None,
+        }
+    }
+
+    /// Returns a `Future` that resolves once the `AsyncMvar` is empty, inserting `value`.
+    pub fn put(&self, value: T) -> Put<'_, T> {
+        Put {
+            mvar: self,
+            value: Some(value),
+            waker: None,
+        }
+    }
+}
+
+impl<T: Clone> AsyncMvar<T> {
+    /// Returns a `Future` that resolves to a clone of the value once the `AsyncMvar` is full,
+    /// without removing it.
+    pub fn read(&self) -> Read<'_, T> {
+        Read {
+            mvar: self,
+            waker: None,
+        }
+    }
+}
+
+/// Future returned by [`AsyncMvar::take`].
+#[derive(Debug)]
+pub struct Take<'a, T> {
+    mvar: &'a AsyncMvar<T>,
+    waker: Option<Waker>,
+}
+
+// `Take` holds no self-referential data, so it's safe to move even when `T` isn't `Unpin`.
+impl<'a, T> Unpin for Take<'a, T> {}
+
+impl<'a, T> Future for Take<'a, T> {
+    type Output = T;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<T> {
+        let this = self.get_mut();
+        let mut state = this.mvar.lock();
+        match state.value.take() {
+            Some(value) => {
+                if let Some(waker) = state.put_waiters.pop_front() {
+                    waker.wake();
+                }
+                Poll::Ready(value)
+            }
+            None => {
+                register_waker(&mut state.take_waiters, cx.waker());
+                this.waker = Some(cx.waker().clone());
+                Poll::Pending
+            }
+        }
+    }
+}
+
+impl<'a, T> Drop for Take<'a, T> {
+    fn drop(&mut self) {
+        // If this future is being dropped before completing (e.g. the losing branch of a
+        // `select!`, or a timeout), its waker is still sitting in `take_waiters`. Left there,
+        // the next `put`/`try_put` would `pop_front()` it and wake a task that's already gone,
+        // instead of the real next waiter behind it — so remove our own registration first.
+        if let Some(waker) = self.waker.take() {
+            let mut state = self.mvar.lock();
+            state.take_waiters.retain(|w| !w.will_wake(&waker));
+        }
+    }
+}
+
+/// Future returned by [`AsyncMvar::put`].
+#[derive(Debug)]
+pub struct Put<'a, T> {
+    mvar: &'a AsyncMvar<T>,
+    value: Option<T>,
+    waker: Option<Waker>,
+}
+
+// `Put` holds no self-referential data, so it's safe to move even when `T` isn't `Unpin`.
+impl<'a, T> Unpin for Put<'a, T> {}
+
+impl<'a, T> Future for Put<'a, T> {
+    type Output = ();
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<()> {
+        let this = self.get_mut();
+        let mut state = this.mvar.lock();
+        if state.value.is_none() {
+            state.value = this.value.take();
+            if let Some(waker) = state.take_waiters.pop_front() {
+                waker.wake();
+            }
+            for waker in state.read_waiters.drain(..) {
+                waker.wake();
+            }
+            Poll::Ready(())
+        } else {
+            register_waker(&mut state.put_waiters, cx.waker());
+            this.waker = Some(cx.waker().clone());
+            Poll::Pending
+        }
+    }
+}
+
+impl<'a, T> Drop for Put<'a, T> {
+    fn drop(&mut self) {
+        // See `Take`'s `Drop` impl: a dropped-before-completion `Put` must deregister its own
+        // waker from `put_waiters`, or a later `take` can wake a task that's already gone
+        // instead of the real next waiter.
+        if let Some(waker) = self.waker.take() {
+            let mut state = self.mvar.lock();
+            state.put_waiters.retain(|w| !w.will_wake(&waker));
+        }
+    }
+}
+
+/// Future returned by [`AsyncMvar::read`].
+#[derive(Debug)]
+pub struct Read<'a, T> {
+    mvar: &'a AsyncMvar<T>,
+    waker: Option<Waker>,
+}
+
+// `Read` holds no self-referential data, so it's safe to move even when `T` isn't `Unpin`.
+impl<'a, T> Unpin for Read<'a, T> {}
+
+impl<'a, T: Clone> Future for Read<'a, T> {
+    type Output = T;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<T> {
+        let this = self.get_mut();
+        let mut state = this.mvar.lock();
+        match state.value.clone() {
+            Some(value) => Poll::Ready(value),
+            None => {
+                register_waker(&mut state.read_waiters, cx.waker());
+                this.waker = Some(cx.waker().clone());
+                Poll::Pending
+            }
+        }
+    }
+}
+
+impl<'a, T> Drop for Read<'a, T> {
+    fn drop(&mut self) {
+        // See `Take`'s `Drop` impl: a dropped-before-completion `Read` must deregister its own
+        // waker from `read_waiters`, or a later `put` can wake a task that's already gone
+        // instead of the real next waiter.
+        if let Some(waker) = self.waker.take() {
+            let mut state = self.mvar.lock();
+            state.read_waiters.retain(|w| !w.will_wake(&waker));
+        }
+    }
+}
+
+#[cfg(all(feature = "shuttle", test))]
+mod tests {
+    use super::*;
+
+    use shuttle::future::block_on;
+    use shuttle::thread;
+    use std::sync::atomic::{AtomicBool, Ordering};
+    use std::sync::Arc;
+    use std::task::Wake;
+
+    /// A `Waker` that just remembers whether it was ever fired, so a test can tell which of two
+    /// competing registrations a `put`/`take` actually woke.
+    struct TrackWaker(AtomicBool);
+
+    impl Wake for TrackWaker {
+        fn wake(self: Arc<Self>) {
+            self.wake_by_ref();
+        }
+
+        fn wake_by_ref(self: &Arc<Self>) {
+            self.0.store(true, Ordering::SeqCst);
+        }
+    }
+
+    #[test]
+    fn cancelled_take_does_not_block_the_next_take() {
+        shuttle::check_pct(
+            || {
+                let mvar = AsyncMvar::<i32>::empty();
+
+                let ghost = Arc::new(TrackWaker(AtomicBool::new(false)));
+                let ghost_waker = Waker::from(ghost.clone());
+                let mut ghost_cx = Context::from_waker(&ghost_waker);
+
+                // Poll a `take` once so it registers a waker, then drop it before it resolves —
+                // the same thing a losing `select!` branch or a timeout does. `Drop` must remove
+                // that registration, or its stale waker below ends up "winning" the `put` meant
+                // for the real next taker instead of the ghost one just being a harmless no-op.
+                let mut cancelled = Box::pin(mvar.take());
+                assert!(cancelled.as_mut().poll(&mut ghost_cx).is_pending());
+                drop(cancelled);
+
+                let real = Arc::new(TrackWaker(AtomicBool::new(false)));
+                let real_waker = Waker::from(real.clone());
+                let mut real_cx = Context::from_waker(&real_waker);
+                let mut waiting = Box::pin(mvar.take());
+                assert!(waiting.as_mut().poll(&mut real_cx).is_pending());
+
+                // Exercise real thread concurrency, as `check_pct` requires; it doesn't affect
+                // the outcome under test, which only depends on which waker `put` fires.
+                thread::spawn(|| {}).join().unwrap();
+
+                block_on(mvar.put(7));
+
+                assert!(
+                    !ghost.0.load(Ordering::SeqCst),
+                    "the dropped `take`'s stale waker fired instead of the real one"
+                );
+                assert!(real.0.load(Ordering::SeqCst));
+                assert_eq!(waiting.as_mut().poll(&mut real_cx), Poll::Ready(7));
+            },
+            100,
+            2,
+        )
+    }
+}